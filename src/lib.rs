@@ -18,13 +18,26 @@
 /// 
 /// // Asks the user for enter the input through the terminal
 /// macro_env!(Input);
-/// 
-/// // All, and not specifying the searchtype, will try to find the variable through all 3 methods:
-/// // First it checks for a .env file
+///
+/// // Fetch "OS_FILE" as a path and return the trimmed contents of that file,
+/// // matching the Docker/Kubernetes secrets-as-files convention
+/// macro_env!(SecretFile, "OS");
+///
+/// // All, and not specifying the searchtype, will try to find the variable through all 4 methods:
+/// // First it checks for a secret file
+/// // Then it checks for a .env file
 /// // Then by searching for a system variable
-/// // And if both fail, it will ask the user for input
+/// // And if all fail, it will ask the user for input
 /// macro_env!(All, "OS");
 /// macro_env!("OS");
+///
+/// // With the "typed" feature enabled, a type can be added to parse the
+/// // result instead of getting a `String` back:
+/// // macro_env!(File, "PORT", u16);
+///
+/// // `os` fetches the variable as an `OsString` instead of a `String`, for
+/// // values that aren't guaranteed to be valid Unicode:
+/// // macro_env!(System, "PATH", os);
 ///```
 #[macro_export]
 macro_rules! macro_env {
@@ -37,82 +50,373 @@ macro_rules! macro_env {
     (Input) => {{
         input().unwrap()
     }};
+    (SecretFile, $envvariablename:literal) => {{
+        secretfilereader($envvariablename.to_string()).unwrap()
+    }};
     (All, $envvariablename:literal) => {{
-        let resultenv = dotenvreader($envvariablename.to_string());
-        if resultenv.is_ok() {
-            resultenv.unwrap()
-        } else if systemreader($envvariablename.to_string()).is_ok() {
-            systemreader($envvariablename.to_string()).unwrap()
+        let resultsecret = secretfilereader($envvariablename.to_string());
+        if let Ok(value) = resultsecret {
+            value
+        } else if let Ok(value) = dotenvreader($envvariablename.to_string()) {
+            value
+        } else if let Ok(value) = systemreader($envvariablename.to_string()) {
+            value
         } else {
-            input().unwrap()
+            input_with($envvariablename).unwrap()
         }
     }};
     ($envvariablename:literal) => {{
-        let resultenv = dotenvreader($envvariablename.to_string());
-        if resultenv.is_ok() {
-            resultenv.unwrap()
-        } else if systemreader($envvariablename.to_string()).is_ok() {
-            systemreader($envvariablename.to_string()).unwrap()
+        let resultsecret = secretfilereader($envvariablename.to_string());
+        if let Ok(value) = resultsecret {
+            value
+        } else if let Ok(value) = dotenvreader($envvariablename.to_string()) {
+            value
+        } else if let Ok(value) = systemreader($envvariablename.to_string()) {
+            value
         } else {
-            input().unwrap()
+            input_with($envvariablename).unwrap()
         }
     }};
+    (File, $envvariablename:literal, os) => {{
+        dotenvreader_os($envvariablename.to_string()).unwrap()
+    }};
+    (System, $envvariablename:literal, os) => {{
+        systemreader_os($envvariablename.to_string()).unwrap()
+    }};
+    (File, $envvariablename:literal, $type:ty) => {{
+        $crate::typedenv::<$type>($crate::SearchType::Envfile, $envvariablename).unwrap()
+    }};
+    (System, $envvariablename:literal, $type:ty) => {{
+        $crate::typedenv::<$type>($crate::SearchType::System, $envvariablename).unwrap()
+    }};
+    (All, $envvariablename:literal, $type:ty) => {{
+        $crate::typedenv::<$type>($crate::SearchType::All, $envvariablename).unwrap()
+    }};
+    ($envvariablename:literal, $type:ty) => {{
+        $crate::typedenv::<$type>($crate::SearchType::All, $envvariablename).unwrap()
+    }};
+}
+
+/// Parses a `.env`-style file into a map of all the variables it defines.
+///
+/// Blank lines and lines starting with `#` are skipped, an optional leading
+/// `export ` is stripped from each key, and values may be wrapped in matching
+/// single or double quotes. Double-quoted values additionally support `\n`
+/// and `\"` escapes.
+///
+/// # Example
+/// ```rust
+/// use macro_env::parse_env_file;
+///
+/// let variables = parse_env_file(".env").unwrap();
+/// let envvariable = variables.get("OS").unwrap();
+/// ```
+pub fn parse_env_file(
+    path: impl AsRef<std::path::Path>,
+) -> Result<std::collections::HashMap<String, String>, std::io::Error> {
+    let contents = std::fs::read_to_string(path)?;
+    let mut variables = std::collections::HashMap::new();
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line = line.strip_prefix("export ").unwrap_or(line);
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim();
+        if key.is_empty() {
+            continue;
+        }
+
+        let value = if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+            value[1..value.len() - 1]
+                .replace("\\n", "\n")
+                .replace("\\\"", "\"")
+        } else if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+            value[1..value.len() - 1].to_string()
+        } else {
+            value.to_string()
+        };
+
+        variables.insert(key.to_string(), value);
+    }
+
+    Ok(variables)
 }
 
 /// Reads the .env file and tries to find the .env variable.
-/// 
+///
 /// # Example
 /// ```rust
 /// use macro_env::dotenvreader;
-/// 
+///
 /// let envvariable :String = dotenvreader("OS".to_string()).unwrap();
 /// ```
 pub fn dotenvreader(envvariablename: String) -> Result<String, std::io::Error> {
-    let file = std::fs::File::open(".env")?;
-    let reader = std::io::BufReader::new(file);
-    let mut token = String::new();
-    use std::io::BufRead;
-
-    for line in reader.lines() {
-        if let Ok(line) = line {
-            let parts: Vec<&str> = line.splitn(2, '=').collect();
-            if parts.len() == 2 && parts[0] == envvariablename && !parts[1].is_empty() {
-                token = parts[1].to_string();
+    let variables = parse_env_file(".env")?;
+    variables.get(&envvariablename).cloned().ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "Couldn't find the variable requested in the .env",
+        )
+    })
+}
+
+/// Parses a layered list of `.env` files into a single map, where variables from
+/// files later in the list override the same variable from earlier files.
+///
+/// A file that doesn't exist is treated as an optional layer that contributes
+/// nothing, so deployments can list `.env`, `.env.local`, `.env.production`
+/// without every layer having to be present. Other IO errors (e.g. permissions)
+/// still propagate.
+pub fn parse_env_files(
+    paths: &[std::path::PathBuf],
+) -> Result<std::collections::HashMap<String, String>, std::io::Error> {
+    let mut merged = std::collections::HashMap::new();
+    for path in paths {
+        match parse_env_file(path) {
+            Ok(variables) => merged.extend(variables),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(merged)
+}
+
+/// Like `dotenvreader()`, but looks the variable up across a layered list of
+/// `.env` files instead of the single `.env` in the current directory.
+///
+/// # Example
+/// ```rust
+/// use macro_env::dotenvreader_from;
+///
+/// let paths = vec![".env".into(), ".env.local".into()];
+/// let envvariable: String = dotenvreader_from(&paths, "OS").unwrap();
+/// ```
+pub fn dotenvreader_from(
+    paths: &[std::path::PathBuf],
+    envvariablename: &str,
+) -> Result<String, std::io::Error> {
+    let variables = parse_env_files(paths)?;
+    variables.get(envvariablename).cloned().ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "Couldn't find the variable requested in the provided .env files",
+        )
+    })
+}
+
+/// Walks up from the current directory through its parents looking for a `.env`
+/// file, the way common dotenv tooling does.
+///
+/// # Example
+/// ```rust
+/// use macro_env::find_dotenv;
+///
+/// let path = find_dotenv();
+/// ```
+pub fn find_dotenv() -> Option<std::path::PathBuf> {
+    let mut dir = std::env::current_dir().ok()?;
+    loop {
+        let candidate = dir.join(".env");
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+/// Configuration for locating and layering `.env` files, for deployments that
+/// split configuration across e.g. `.env`, `.env.local` and `.env.production`.
+///
+/// # Example
+/// ```rust
+/// use macro_env::EnvFileConfig;
+///
+/// let config = EnvFileConfig::new()
+///     .with_file(".env")
+///     .with_file(".env.local");
+/// let variables = config.load().unwrap();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct EnvFileConfig {
+    paths: Vec<std::path::PathBuf>,
+}
+
+impl EnvFileConfig {
+    /// Creates an empty configuration with no files to load.
+    pub fn new() -> Self {
+        Self { paths: Vec::new() }
+    }
+
+    /// Creates a configuration seeded with the `.env` found by walking up from
+    /// the current directory, if any.
+    pub fn discover() -> Self {
+        let mut config = Self::new();
+        if let Some(path) = find_dotenv() {
+            config = config.with_file(path);
+        }
+        config
+    }
+
+    /// Adds a `.env` file to the layering; files added later override values
+    /// from files added earlier.
+    pub fn with_file(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.paths.push(path.into());
+        self
+    }
+
+    /// Parses every configured file, merging them so later files override earlier ones.
+    pub fn load(&self) -> Result<std::collections::HashMap<String, String>, std::io::Error> {
+        parse_env_files(&self.paths)
+    }
+}
+
+fn trim_ascii_whitespace(mut bytes: &[u8]) -> &[u8] {
+    while let [first, rest @ ..] = bytes {
+        if first.is_ascii_whitespace() {
+            bytes = rest;
+        } else {
+            break;
+        }
+    }
+    while let [rest @ .., last] = bytes {
+        if last.is_ascii_whitespace() {
+            bytes = rest;
+        } else {
+            break;
+        }
+    }
+    bytes
+}
+
+fn unquote_bytes(value: &[u8]) -> Vec<u8> {
+    if value.len() >= 2 && value.first() == Some(&b'"') && value.last() == Some(&b'"') {
+        let inner = &value[1..value.len() - 1];
+        let mut result = Vec::with_capacity(inner.len());
+        let mut iter = inner.iter().copied().peekable();
+        while let Some(byte) = iter.next() {
+            if byte == b'\\' {
+                match iter.peek() {
+                    Some(b'n') => {
+                        result.push(b'\n');
+                        iter.next();
+                    }
+                    Some(b'"') => {
+                        result.push(b'"');
+                        iter.next();
+                    }
+                    _ => result.push(byte),
+                }
             } else {
-                return Err(std::io::Error::new(
-                    std::io::ErrorKind::NotFound,
-                    "Couldn't find the variable requested in the .env",
-                ));
+                result.push(byte);
             }
         }
+        result
+    } else if value.len() >= 2 && value.first() == Some(&b'\'') && value.last() == Some(&b'\'') {
+        value[1..value.len() - 1].to_vec()
+    } else {
+        value.to_vec()
     }
+}
 
-    if token.is_empty() {
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::NotFound,
-            "There doesn't seem to be a variable in the .env",
-        ));
+fn find_dotenv_value_bytes(contents: &[u8], envvariablename: &str) -> Option<Vec<u8>> {
+    for line in contents.split(|&byte| byte == b'\n') {
+        let line = trim_ascii_whitespace(line);
+        if line.is_empty() || line.first() == Some(&b'#') {
+            continue;
+        }
+
+        let line = line.strip_prefix(b"export ").unwrap_or(line);
+
+        let Some(eq) = line.iter().position(|&byte| byte == b'=') else {
+            continue;
+        };
+        let key = trim_ascii_whitespace(&line[..eq]);
+        if key != envvariablename.as_bytes() {
+            continue;
+        }
+
+        let value = trim_ascii_whitespace(&line[eq + 1..]);
+        return Some(unquote_bytes(value));
     }
+    None
+}
 
-    if token.ends_with('"') && token.starts_with('"') {
-        token.pop();
-        token.remove(0);
-    };
+#[cfg(unix)]
+fn os_string_from_bytes(bytes: &[u8]) -> std::ffi::OsString {
+    use std::os::unix::ffi::OsStringExt;
+    std::ffi::OsString::from_vec(bytes.to_vec())
+}
+
+// Non-Unix platforms don't expose a lossless bytes <-> OsString conversion in
+// std, so fall back to a lossy decode there.
+#[cfg(not(unix))]
+fn os_string_from_bytes(bytes: &[u8]) -> std::ffi::OsString {
+    std::ffi::OsString::from(String::from_utf8_lossy(bytes).into_owned())
+}
+
+/// Reads the .env file and tries to find the .env variable, without requiring it (or the rest of the file) to be valid Unicode.
+///
+/// # Example
+/// ```rust
+/// use macro_env::dotenvreader_os;
+///
+/// let envvariable: std::ffi::OsString = dotenvreader_os("OS".to_string()).unwrap();
+/// ```
+pub fn dotenvreader_os(envvariablename: String) -> Result<std::ffi::OsString, std::io::Error> {
+    let contents = std::fs::read(".env")?;
+    find_dotenv_value_bytes(&contents, &envvariablename)
+        .map(|value| os_string_from_bytes(&value))
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "Couldn't find the variable requested in the .env",
+            )
+        })
+}
 
-    Ok(token)
+/// Environment variable that, when set, opts out of interactive input even if
+/// a terminal is attached, so scripted or CI runs can force a fast failure
+/// instead of relying on stdin not being a tty.
+pub const NO_INPUT_VAR: &str = "MACRO_ENV_NO_INPUT";
+
+/// Whether `input()`/`input_with()` should refuse to block on stdin: either no
+/// interactive terminal is attached, or the user opted out via `NO_INPUT_VAR`.
+fn no_interaction() -> bool {
+    use std::io::IsTerminal;
+    std::env::var(NO_INPUT_VAR).is_ok() || !std::io::stdin().is_terminal()
 }
 
 /// Request user input
 /// `input()` fetches stdin.read_lines() and then trims them.
-/// 
+///
+/// Returns an error instead of blocking on stdin when no interactive terminal
+/// is attached, or when `MACRO_ENV_NO_INPUT` is set, so twelve-factor style
+/// deployments fail fast rather than hanging in CI.
+///
 /// # Example
 /// ```rust
 /// use macro_env::input;
-/// 
+///
 /// // Request the user to input a variable
 /// let envvariable :String = input().unwrap();
 /// ```
 pub fn input() -> Result<String, std::io::Error> {
+    if no_interaction() {
+        return Err(std::io::Error::other(
+            "No interactive terminal available to ask for an environment variable",
+        ));
+    }
+
     let mut input = String::new();
     println!("Please enter an environment variable");
     std::io::stdin().read_line(&mut input)?;
@@ -120,18 +424,111 @@ pub fn input() -> Result<String, std::io::Error> {
     Ok(input)
 }
 
+/// Like `input()`, but includes the requested variable's name in the prompt,
+/// so the user (or a CI log) knows exactly which variable is missing.
+///
+/// Returns an error instead of blocking on stdin when no interactive terminal
+/// is attached, or when `MACRO_ENV_NO_INPUT` is set, so twelve-factor style
+/// deployments fail fast rather than hanging in CI.
+///
+/// # Example
+/// ```rust
+/// use macro_env::input_with;
+///
+/// // Request the user to input the "OS" variable
+/// let envvariable :String = input_with("OS").unwrap();
+/// ```
+pub fn input_with(envvariablename: &str) -> Result<String, std::io::Error> {
+    if no_interaction() {
+        return Err(std::io::Error::other(format!(
+            "No interactive terminal available to ask for \"{envvariablename}\""
+        )));
+    }
+
+    let mut input = String::new();
+    println!("Please enter the environment variable \"{envvariablename}\"");
+    std::io::stdin().read_line(&mut input)?;
+    input = input.trim().to_string();
+    Ok(input)
+}
+
 /// Fetch the environment variable from the system environment variable
-/// 
+///
 /// # Example
 /// ```rust
 /// use macro_env::systemreader;
-/// 
+///
 /// // Using systemreader is just a shortcut for std::env::var()
 /// let envvariable :String = systemreader("OS".to_string()).unwrap();
 /// ```
 pub fn systemreader(envvariablename: String) -> Result<String, std::env::VarError> {
     std::env::var(envvariablename)
 }
+
+/// Fetch the environment variable from the system environment variable, without requiring it to be valid Unicode.
+///
+/// # Example
+/// ```rust
+/// use macro_env::systemreader_os;
+///
+/// // Using systemreader_os is just a shortcut for std::env::var_os()
+/// let envvariable: std::ffi::OsString = systemreader_os("OS".to_string()).unwrap();
+/// ```
+pub fn systemreader_os(envvariablename: String) -> Result<std::ffi::OsString, std::io::Error> {
+    std::env::var_os(envvariablename).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "Couldn't find the requested system variable",
+        )
+    })
+}
+
+/// Fetch the environment variable through Docker/Kubernetes style secret files.
+///
+/// Looks up `<NAME>_FILE` as a system environment variable; if it's set, its
+/// value is treated as a path, and the (trimmed) contents of that file are
+/// returned as the variable's value.
+///
+/// # Example
+/// ```rust
+/// use macro_env::secretfilereader;
+///
+/// // If `DATABASE_PASSWORD_FILE=/run/secrets/db_pw` is set, this reads
+/// // and trims the contents of /run/secrets/db_pw
+/// let secret = secretfilereader("DATABASE_PASSWORD".to_string());
+/// ```
+pub fn secretfilereader(envvariablename: String) -> Result<String, std::io::Error> {
+    let path = secretfile_path(&envvariablename)?;
+    let contents = std::fs::read_to_string(path)?;
+    Ok(contents.trim().to_string())
+}
+
+/// Fetch the environment variable through Docker/Kubernetes style secret files,
+/// without requiring the file's contents to be valid Unicode.
+///
+/// # Example
+/// ```rust
+/// use macro_env::secretfilereader_os;
+///
+/// // If `DATABASE_PASSWORD_FILE=/run/secrets/db_pw` is set, this reads
+/// // and trims the contents of /run/secrets/db_pw
+/// let secret = secretfilereader_os("DATABASE_PASSWORD".to_string());
+/// ```
+pub fn secretfilereader_os(envvariablename: String) -> Result<std::ffi::OsString, std::io::Error> {
+    let path = secretfile_path(&envvariablename)?;
+    let contents = std::fs::read(path)?;
+    Ok(os_string_from_bytes(trim_ascii_whitespace(&contents)))
+}
+
+fn secretfile_path(envvariablename: &str) -> Result<String, std::io::Error> {
+    std::env::var(format!("{envvariablename}_FILE")).map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "No _FILE variable set for this environment variable",
+        )
+    })
+}
+
 /// Searchtype for the `fn envseeker()`, this will define what type of search it performs
 pub enum SearchType {
     /// Searching for a .env file
@@ -140,7 +537,9 @@ pub enum SearchType {
     System,
     /// Requesting user input
     Input,
-    /// First searching for a .env file, then search for a system variable, and finally request the user to input one if all fails
+    /// Searching for a `<NAME>_FILE` system variable pointing at a secret file, such as those mounted by Docker/Kubernetes
+    SecretFile,
+    /// First searching for a secret file, then a .env file, then a system variable, and finally requesting the user to input one if all fails
     All,
 }
 
@@ -161,30 +560,404 @@ pub enum SearchType {
 /// 
 /// // Request user input
 /// let inputvariable :String = envseeker(Input, "OS");
-/// 
-/// // Perform all three methods to find a variable
-/// let allvariable :String = envseeker(All, "OS"); 
+///
+/// // Fetch a variable through its `<NAME>_FILE` secret file indirection
+/// let secretvariable :String = envseeker(SecretFile, "OS");
+///
+/// // Perform all four methods to find a variable
+/// let allvariable :String = envseeker(All, "OS");
 /// ```
 pub fn envseeker(searchtype: SearchType, envvariablename: &str) -> String {
     match searchtype {
         SearchType::System => systemreader(envvariablename.to_string()).unwrap(),
         SearchType::Envfile => dotenvreader(envvariablename.to_string()).unwrap(),
-        SearchType::Input => input().unwrap(),
+        SearchType::Input => input_with(envvariablename).unwrap(),
+        SearchType::SecretFile => secretfilereader(envvariablename.to_string()).unwrap(),
+        SearchType::All => {
+            let resultsecret = secretfilereader(envvariablename.to_string());
+            if let Ok(value) = resultsecret {
+                value
+            } else if let Ok(value) = dotenvreader(envvariablename.to_string()) {
+                value
+            } else if let Ok(value) = systemreader(envvariablename.to_string()) {
+                value
+            } else {
+                input_with(envvariablename).unwrap()
+            }
+        }
+    }
+}
+
+/// A function instead of a macro to find the environment variable, without requiring it to be valid Unicode.
+///
+/// # Example
+/// ```rust
+/// use macro_env::*;
+/// use macro_env::SearchType::*;
+///
+/// // Fetch a systemvariable losslessly, even if it isn't valid Unicode
+/// let systemvariable: std::ffi::OsString = envseeker_os(System, "PATH");
+/// ```
+pub fn envseeker_os(searchtype: SearchType, envvariablename: &str) -> std::ffi::OsString {
+    match searchtype {
+        SearchType::System => systemreader_os(envvariablename.to_string()).unwrap(),
+        SearchType::Envfile => dotenvreader_os(envvariablename.to_string()).unwrap(),
+        SearchType::Input => std::ffi::OsString::from(input_with(envvariablename).unwrap()),
+        SearchType::SecretFile => secretfilereader_os(envvariablename.to_string()).unwrap(),
+        SearchType::All => {
+            let resultsecret = secretfilereader_os(envvariablename.to_string());
+            if let Ok(value) = resultsecret {
+                value
+            } else if let Ok(value) = dotenvreader_os(envvariablename.to_string()) {
+                value
+            } else if let Ok(value) = systemreader_os(envvariablename.to_string()) {
+                value
+            } else {
+                std::ffi::OsString::from(input_with(envvariablename).unwrap())
+            }
+        }
+    }
+}
+
+/// Like `envseeker()`, but resolves `Envfile` lookups against an explicit,
+/// layered list of `.env` files instead of the single `.env` in the current
+/// directory. Use `find_dotenv()` to locate one by walking up parent
+/// directories first.
+///
+/// # Example
+/// ```rust
+/// use macro_env::*;
+/// use macro_env::SearchType::*;
+///
+/// let paths = vec![".env".into(), ".env.local".into()];
+/// let filevariable :String = envseeker_from(&paths, Envfile, "OS");
+/// ```
+pub fn envseeker_from(
+    paths: &[std::path::PathBuf],
+    searchtype: SearchType,
+    envvariablename: &str,
+) -> String {
+    match searchtype {
+        SearchType::System => systemreader(envvariablename.to_string()).unwrap(),
+        SearchType::Envfile => dotenvreader_from(paths, envvariablename).unwrap(),
+        SearchType::Input => input_with(envvariablename).unwrap(),
+        SearchType::SecretFile => secretfilereader(envvariablename.to_string()).unwrap(),
         SearchType::All => {
-            let resultenv = dotenvreader(envvariablename.to_string());
-            if resultenv.is_ok() {
-                resultenv.unwrap()
-            } else if systemreader(envvariablename.to_string().clone()).is_ok() {
-                systemreader(envvariablename.to_string()).unwrap()
+            let resultsecret = secretfilereader(envvariablename.to_string());
+            if let Ok(value) = resultsecret {
+                value
+            } else if let Ok(value) = dotenvreader_from(paths, envvariablename) {
+                value
+            } else if let Ok(value) = systemreader(envvariablename.to_string()) {
+                value
             } else {
-                input().unwrap()
+                input_with(envvariablename).unwrap()
             }
         }
     }
 }
 
+/// Error returned by `typedenv()`, distinguishing a missing variable from one
+/// that was found but couldn't be parsed into the requested type.
+#[cfg(feature = "typed")]
+#[derive(Debug)]
+pub enum TypedEnvError<E> {
+    /// The variable could not be found through the requested search type, carrying
+    /// the underlying lookup error's message (e.g. why a file read or input prompt failed).
+    NotFound(String),
+    /// The variable was found, but parsing it into the requested type failed.
+    ParseError(E),
+}
 
 #[cfg(feature = "typed")]
-pub fn typedenv() {
-    println!("Hello from the typed world");
+impl<E: std::fmt::Display> std::fmt::Display for TypedEnvError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypedEnvError::NotFound(message) => {
+                write!(f, "Couldn't find the requested environment variable: {message}")
+            }
+            TypedEnvError::ParseError(error) => write!(f, "Found the environment variable, but couldn't parse it: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "typed")]
+impl<E: std::fmt::Debug + std::fmt::Display> std::error::Error for TypedEnvError<E> {}
+
+/// Fetches an environment variable like `envseeker()`, then parses it into `T`.
+///
+/// # Example
+/// ```rust
+/// use macro_env::*;
+/// use macro_env::SearchType::*;
+///
+/// // Fetch "PORT" and parse it as a u16
+/// let port: u16 = typedenv(Envfile, "PORT").unwrap();
+/// ```
+#[cfg(feature = "typed")]
+pub fn typedenv<T: std::str::FromStr>(
+    searchtype: SearchType,
+    envvariablename: &str,
+) -> Result<T, TypedEnvError<T::Err>> {
+    let raw = match searchtype {
+        SearchType::System => systemreader(envvariablename.to_string())
+            .map_err(|error| TypedEnvError::NotFound(error.to_string()))?,
+        SearchType::Envfile => dotenvreader(envvariablename.to_string())
+            .map_err(|error| TypedEnvError::NotFound(error.to_string()))?,
+        SearchType::Input => input_with(envvariablename)
+            .map_err(|error| TypedEnvError::NotFound(error.to_string()))?,
+        SearchType::SecretFile => secretfilereader(envvariablename.to_string())
+            .map_err(|error| TypedEnvError::NotFound(error.to_string()))?,
+        SearchType::All => {
+            let resultsecret = secretfilereader(envvariablename.to_string());
+            if let Ok(value) = resultsecret {
+                value
+            } else if let Ok(value) = dotenvreader(envvariablename.to_string()) {
+                value
+            } else if let Ok(value) = systemreader(envvariablename.to_string()) {
+                value
+            } else {
+                input_with(envvariablename)
+                    .map_err(|error| TypedEnvError::NotFound(error.to_string()))?
+            }
+        }
+    };
+
+    raw.parse::<T>().map_err(TypedEnvError::ParseError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp_env(contents: &str) -> std::path::PathBuf {
+        static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        let id = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+        let mut path = std::env::temp_dir();
+        path.push(format!("macro_env_test_{}_{id}.env", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn parse_env_file_skips_comments_and_blank_lines() {
+        let path = write_temp_env("\n# a comment\nOS=linux\n\n");
+        let variables = parse_env_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(variables.get("OS"), Some(&"linux".to_string()));
+        assert_eq!(variables.len(), 1);
+    }
+
+    #[test]
+    fn parse_env_file_strips_export_prefix() {
+        let path = write_temp_env("export PORT=8080\n");
+        let variables = parse_env_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(variables.get("PORT"), Some(&"8080".to_string()));
+    }
+
+    #[test]
+    fn parse_env_file_strips_matching_quotes() {
+        let path = write_temp_env("SINGLE='hello'\nDOUBLE=\"world\"\n");
+        let variables = parse_env_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(variables.get("SINGLE"), Some(&"hello".to_string()));
+        assert_eq!(variables.get("DOUBLE"), Some(&"world".to_string()));
+    }
+
+    #[test]
+    fn parse_env_file_handles_escapes_in_double_quotes() {
+        let path = write_temp_env(
+            "MULTILINE=\"line1\\nline2\"\nQUOTED=\"she said \\\"hi\\\"\"\n",
+        );
+        let variables = parse_env_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(variables.get("MULTILINE"), Some(&"line1\nline2".to_string()));
+        assert_eq!(variables.get("QUOTED"), Some(&"she said \"hi\"".to_string()));
+    }
+
+    fn unique_id() -> usize {
+        static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    }
+
+    #[test]
+    fn secretfilereader_reads_and_trims_the_referenced_file() {
+        let name = format!("MACRO_ENV_TEST_SECRET_{}", unique_id());
+        let path = write_temp_env("  super-secret-value  \n");
+        std::env::set_var(format!("{name}_FILE"), &path);
+
+        let value = secretfilereader(name.clone()).unwrap();
+
+        std::env::remove_var(format!("{name}_FILE"));
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(value, "super-secret-value");
+    }
+
+    #[test]
+    fn secretfilereader_errors_when_no_file_variable_is_set() {
+        let name = format!("MACRO_ENV_TEST_SECRET_MISSING_{}", unique_id());
+        assert!(secretfilereader(name).is_err());
+    }
+
+    #[test]
+    fn envseeker_secretfile_reads_through_the_file_indirection() {
+        let name = format!("MACRO_ENV_TEST_SECRET_ENVSEEKER_{}", unique_id());
+        let path = write_temp_env("seeker-value");
+        std::env::set_var(format!("{name}_FILE"), &path);
+
+        let value = envseeker(SearchType::SecretFile, &name);
+
+        std::env::remove_var(format!("{name}_FILE"));
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(value, "seeker-value");
+    }
+
+    #[test]
+    fn find_dotenv_value_bytes_skips_comments_and_export_prefix() {
+        let contents = b"# a comment\n\nexport OS=linux\n";
+        assert_eq!(
+            find_dotenv_value_bytes(contents, "OS"),
+            Some(b"linux".to_vec())
+        );
+    }
+
+    #[test]
+    fn find_dotenv_value_bytes_is_unaffected_by_non_utf8_bytes_elsewhere_in_the_file() {
+        let mut contents = b"OK=value\nBAD=".to_vec();
+        contents.extend_from_slice(&[0xff, 0xfe]);
+        contents.push(b'\n');
+
+        assert_eq!(find_dotenv_value_bytes(&contents, "OK"), Some(b"value".to_vec()));
+    }
+
+    #[test]
+    fn unquote_bytes_handles_double_quote_escapes() {
+        assert_eq!(unquote_bytes(b"\"line1\\nline2\""), b"line1\nline2");
+        assert_eq!(unquote_bytes(b"\"she said \\\"hi\\\"\""), b"she said \"hi\"");
+    }
+
+    #[test]
+    fn unquote_bytes_strips_single_quotes_without_interpreting_escapes() {
+        assert_eq!(unquote_bytes(b"'raw \\n value'"), b"raw \\n value");
+    }
+
+    #[test]
+    fn systemreader_os_returns_the_raw_system_value() {
+        let name = format!("MACRO_ENV_TEST_SYSTEM_OS_{}", unique_id());
+        std::env::set_var(&name, "value");
+
+        let value = systemreader_os(name.clone()).unwrap();
+
+        std::env::remove_var(&name);
+
+        assert_eq!(value, std::ffi::OsString::from("value"));
+    }
+
+    #[test]
+    fn secretfilereader_os_is_lossless_for_non_utf8_file_contents() {
+        let name = format!("MACRO_ENV_TEST_SECRET_OS_{}", unique_id());
+        let bytes = vec![0xff, 0xfe, b'\n'];
+        let mut path = std::env::temp_dir();
+        path.push(format!("macro_env_test_{}_{}.secret", std::process::id(), unique_id()));
+        std::fs::write(&path, &bytes).unwrap();
+        std::env::set_var(format!("{name}_FILE"), &path);
+
+        let value = secretfilereader_os(name.clone()).unwrap();
+
+        std::env::remove_var(format!("{name}_FILE"));
+        std::fs::remove_file(&path).unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStringExt;
+            assert_eq!(value, std::ffi::OsString::from_vec(vec![0xff, 0xfe]));
+        }
+        #[cfg(not(unix))]
+        {
+            assert!(!value.is_empty());
+        }
+    }
+
+    #[test]
+    fn parse_env_files_merges_with_later_files_overriding_earlier_ones() {
+        let base = write_temp_env("SHARED=base\nBASE_ONLY=base\n");
+        let overlay = write_temp_env("SHARED=overlay\nOVERLAY_ONLY=overlay\n");
+
+        let merged = parse_env_files(&[base.clone(), overlay.clone()]).unwrap();
+
+        std::fs::remove_file(&base).unwrap();
+        std::fs::remove_file(&overlay).unwrap();
+
+        assert_eq!(merged.get("SHARED"), Some(&"overlay".to_string()));
+        assert_eq!(merged.get("BASE_ONLY"), Some(&"base".to_string()));
+        assert_eq!(merged.get("OVERLAY_ONLY"), Some(&"overlay".to_string()));
+    }
+
+    #[test]
+    fn parse_env_files_treats_a_missing_optional_layer_as_empty() {
+        let base = write_temp_env("OS=linux\n");
+        let mut missing = std::env::temp_dir();
+        missing.push(format!(
+            "macro_env_test_missing_{}_{}.env",
+            std::process::id(),
+            unique_id()
+        ));
+
+        let merged = parse_env_files(&[base.clone(), missing]).unwrap();
+        std::fs::remove_file(&base).unwrap();
+
+        assert_eq!(merged.get("OS"), Some(&"linux".to_string()));
+    }
+
+    #[test]
+    fn env_file_config_loads_layered_files_via_with_file() {
+        let base = write_temp_env("NAME=base\n");
+        let overlay = write_temp_env("NAME=overlay\n");
+
+        let config = EnvFileConfig::new()
+            .with_file(base.clone())
+            .with_file(overlay.clone());
+        let variables = config.load().unwrap();
+
+        std::fs::remove_file(&base).unwrap();
+        std::fs::remove_file(&overlay).unwrap();
+
+        assert_eq!(variables.get("NAME"), Some(&"overlay".to_string()));
+    }
+
+    #[test]
+    fn envseeker_from_reads_envfile_across_layered_paths() {
+        let base = write_temp_env("SHARED=base\n");
+        let overlay = write_temp_env("SHARED=overlay\n");
+
+        let value = envseeker_from(&[base.clone(), overlay.clone()], SearchType::Envfile, "SHARED");
+
+        std::fs::remove_file(&base).unwrap();
+        std::fs::remove_file(&overlay).unwrap();
+
+        assert_eq!(value, "overlay");
+    }
+
+    #[test]
+    fn input_with_fails_fast_instead_of_blocking_when_no_interaction_is_requested() {
+        std::env::set_var(NO_INPUT_VAR, "1");
+
+        assert!(no_interaction());
+
+        let error = input_with("SOME_VAR").unwrap_err();
+        assert!(error.to_string().contains("SOME_VAR"));
+
+        let error = input().unwrap_err();
+        assert!(!error.to_string().is_empty());
+
+        std::env::remove_var(NO_INPUT_VAR);
+    }
 }
\ No newline at end of file